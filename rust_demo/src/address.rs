@@ -0,0 +1,77 @@
+use crate::error::{Result, WalletError};
+use crate::signer;
+use crate::wallet_type::WalletType;
+
+/// Number of bytes of the hashed public key kept as the address payload.
+const PAYLOAD_LEN: usize = 20;
+/// Number of checksum bytes appended to the payload.
+const CHECKSUM_LEN: usize = 4;
+
+/// Derives a checksummed address from a public key: hash `pk` with the
+/// wallet type's configured hash algorithm, keep the first [`PAYLOAD_LEN`]
+/// bytes as the payload, and append a checksum over that payload so typos
+/// in a copied address are detectable.
+pub fn derive_address(pk: &[u8], w_type: &WalletType) -> Result<String> {
+    let digest = signer::hash(pk, w_type.hash_algorithm);
+    if digest.len() < PAYLOAD_LEN {
+        return Err(WalletError::InvalidKeyLength {
+            expected: PAYLOAD_LEN,
+            actual: digest.len(),
+        });
+    }
+    let payload = &digest[..PAYLOAD_LEN];
+    let checksum = checksum_of(payload);
+    Ok(format!("{}{}", hex::encode(payload), hex::encode(checksum)))
+}
+
+/// Recomputes and checks the checksum embedded in `addr`, catching typos or
+/// corruption without needing the original public key.
+pub fn validate_address(addr: &str) -> Result<()> {
+    if addr.len() != (PAYLOAD_LEN + CHECKSUM_LEN) * 2 {
+        return Err(WalletError::InvalidKeyLength {
+            expected: (PAYLOAD_LEN + CHECKSUM_LEN) * 2,
+            actual: addr.len(),
+        });
+    }
+    let bytes = hex::decode(addr).map_err(|e| WalletError::Encoding(format!("invalid address hex: {e}")))?;
+    let (payload, checksum) = bytes.split_at(PAYLOAD_LEN);
+    if checksum != checksum_of(payload) {
+        return Err(WalletError::AddressMismatch);
+    }
+    Ok(())
+}
+
+fn checksum_of(payload: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let digest = signer::hash(payload, crate::wallet_type::HashAlgorithm::Sha3_256);
+    let mut checksum = [0u8; CHECKSUM_LEN];
+    checksum.copy_from_slice(&digest[..CHECKSUM_LEN]);
+    checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::Wallet;
+
+    #[test]
+    fn from_address_rejects_bad_checksum() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        assert!(Wallet::from_address(&wallet.address).is_ok());
+
+        let mut tampered = wallet.address.clone();
+        let first_char = if tampered.starts_with('0') { '1' } else { '0' };
+        tampered.replace_range(0..1, &first_char.to_string());
+        assert!(Wallet::from_address(&tampered).is_err());
+    }
+
+    #[test]
+    fn from_address_rejects_malformed_input() {
+        assert!(Wallet::from_address("not-an-address").is_err());
+    }
+
+    #[test]
+    fn validate_address_accepts_derived_address() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        assert!(validate_address(&wallet.address).is_ok());
+    }
+}
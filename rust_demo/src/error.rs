@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// Error type shared by the wallet, signing, address, and keystore modules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalletError {
+    /// A key, signature, or address didn't have the expected byte length.
+    InvalidKeyLength { expected: usize, actual: usize },
+    /// A role/key-algorithm/hash-algorithm name wasn't recognized.
+    UnsupportedAlgorithm(String),
+    /// An address didn't match the one derived from its key material, or a
+    /// checksum didn't match its payload.
+    AddressMismatch,
+    /// The operation requires a private key, but this wallet is watch-only.
+    SigningUnavailable,
+    /// Malformed hex, JSON, or key/signature bytes that didn't decode into
+    /// the expected structure.
+    Encoding(String),
+}
+
+impl fmt::Display for WalletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WalletError::InvalidKeyLength { expected, actual } => write!(
+                f,
+                "invalid length: expected {expected} bytes, got {actual}"
+            ),
+            WalletError::UnsupportedAlgorithm(name) => write!(f, "unsupported algorithm: {name}"),
+            WalletError::AddressMismatch => {
+                write!(f, "address does not match its key material or checksum")
+            }
+            WalletError::SigningUnavailable => {
+                write!(f, "signing is unavailable on a watch-only wallet")
+            }
+            WalletError::Encoding(message) => write!(f, "encoding error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for WalletError {}
+
+pub type Result<T> = std::result::Result<T, WalletError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_key_length_displays_expected_and_actual() {
+        let err = WalletError::InvalidKeyLength {
+            expected: 32,
+            actual: 4,
+        };
+        assert_eq!(err.to_string(), "invalid length: expected 32 bytes, got 4");
+    }
+}
@@ -0,0 +1,132 @@
+use std::str::FromStr;
+
+use serde_json::{json, Value};
+
+use crate::error::{Result, WalletError};
+use crate::wallet::Wallet;
+use crate::wallet_type::{HashAlgorithm, KeyAlgorithm, Role, WalletType};
+
+/// Serializes a wallet into a keystore-style JSON document: key material is
+/// hex-encoded, `sk` is omitted entirely for watch-only wallets, and
+/// `address` is included so [`from_json`] can catch a tampered file.
+pub fn to_json(wallet: &Wallet) -> Result<Value> {
+    let mut doc = json!({
+        "role": wallet.w_type.role.as_str(),
+        "key_algorithm": wallet.w_type.key_algorithm.as_str(),
+        "hash_algorithm": wallet.w_type.hash_algorithm.as_str(),
+        "pk": hex::encode(&wallet.pk),
+        "address": wallet.address,
+    });
+    if !wallet.sk.is_empty() {
+        doc["sk"] = json!(hex::encode(&wallet.sk));
+    }
+    Ok(doc)
+}
+
+/// Rebuilds a wallet from a [`to_json`] document, re-deriving the address
+/// from the key material and rejecting the document if it doesn't match the
+/// embedded `address` — this is what catches a tampered or corrupted
+/// keystore file.
+pub fn from_json(j: Value) -> Result<Wallet> {
+    let field = |name: &str| -> Result<String> {
+        j.get(name)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| WalletError::Encoding(format!("keystore document missing `{name}`")))
+    };
+
+    let role = field("role")?;
+    let key_algorithm = field("key_algorithm")?;
+    let hash_algorithm = field("hash_algorithm")?;
+    let address = field("address")?;
+
+    let w_type = WalletType::new(
+        Role::from_str(&role)?,
+        KeyAlgorithm::from_str(&key_algorithm)?,
+        HashAlgorithm::from_str(&hash_algorithm)?,
+    );
+
+    let wallet = match j.get("sk").and_then(Value::as_str) {
+        Some(sk_hex) => {
+            let sk = hex::decode(sk_hex)
+                .map_err(|e| WalletError::Encoding(format!("invalid sk hex: {e}")))?;
+            Wallet::from_sk(&sk, &w_type)?
+        }
+        None => {
+            let pk_hex = field("pk")?;
+            let pk = hex::decode(pk_hex)
+                .map_err(|e| WalletError::Encoding(format!("invalid pk hex: {e}")))?;
+            Wallet::from_pk(&pk, &w_type)?
+        }
+    };
+
+    if wallet.address != address {
+        return Err(WalletError::AddressMismatch);
+    }
+    Ok(wallet)
+}
+
+/// Performs the same address/key consistency check as [`from_json`] without
+/// going through serialization, for validating an already-loaded wallet.
+pub fn is_valid(wallet: &Wallet) -> bool {
+    let rebuilt = if !wallet.sk.is_empty() {
+        Wallet::from_sk(&wallet.sk, &wallet.w_type)
+    } else if !wallet.pk.is_empty() {
+        Wallet::from_pk(&wallet.pk, &wallet.w_type)
+    } else {
+        return false;
+    };
+    match rebuilt {
+        Ok(rebuilt) => rebuilt.address == wallet.address,
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secp256k1_signer() -> WalletType {
+        WalletType::new(Role::Signer, KeyAlgorithm::Secp256k1, HashAlgorithm::Sha3_256)
+    }
+
+    #[test]
+    fn to_json_then_from_json_round_trips() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        let doc = wallet.to_json().unwrap();
+        let reloaded = Wallet::from_json(doc).unwrap();
+        assert_eq!(wallet, reloaded);
+    }
+
+    #[test]
+    fn secp256k1_to_json_then_from_json_round_trips() {
+        let wallet = Wallet::from_wallet_type(&secp256k1_signer()).unwrap();
+        let doc = wallet.to_json().unwrap();
+        let reloaded = Wallet::from_json(doc).unwrap();
+        assert_eq!(wallet, reloaded);
+    }
+
+    #[test]
+    fn from_json_rejects_tampered_address() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        let mut doc = wallet.to_json().unwrap();
+        doc["address"] = json!("0000000000000000000000000000000000000000");
+        assert!(Wallet::from_json(doc).is_err());
+    }
+
+    #[test]
+    fn watch_only_json_omits_sk() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        let watcher = Wallet::from_pk(&wallet.pk, &wallet.w_type).unwrap();
+        let doc = watcher.to_json().unwrap();
+        assert!(doc.get("sk").is_none());
+    }
+
+    #[test]
+    fn is_valid_detects_tampered_wallet() {
+        let mut wallet = Wallet::create_default_wallet().unwrap();
+        assert!(is_valid(&wallet));
+        wallet.address = "tampered".to_string();
+        assert!(!is_valid(&wallet));
+    }
+}
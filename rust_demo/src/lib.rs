@@ -0,0 +1,6 @@
+pub mod address;
+pub mod error;
+pub mod keystore;
+pub mod signer;
+pub mod wallet;
+pub mod wallet_type;
@@ -1,17 +1,8 @@
-fn banner(message: &str) -> String {
-    format!("*** {} ***", message.to_uppercase())
-}
+use rust_demo::wallet::Wallet;
 
 fn main() {
-    println!("{}", banner("codex demo"));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::banner;
-
-    #[test]
-    fn banner_wraps_text() {
-        assert_eq!(banner("demo"), "*** DEMO ***");
+    match Wallet::create_default_wallet() {
+        Ok(wallet) => println!("created wallet {}", wallet.address),
+        Err(err) => eprintln!("failed to create wallet: {err}"),
     }
 }
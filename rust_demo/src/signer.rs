@@ -0,0 +1,212 @@
+use ed25519_dalek::{Signature as Ed25519Signature, Signer as DalekSigner, SigningKey, Verifier as DalekVerifier, VerifyingKey};
+use k256::ecdsa::{
+    signature::hazmat::{PrehashSigner, PrehashVerifier},
+    Signature as Secp256k1Signature, SigningKey as Secp256k1SigningKey,
+    VerifyingKey as Secp256k1VerifyingKey,
+};
+use sha3::{Digest, Sha3_256};
+
+use crate::error::{Result, WalletError};
+use crate::wallet_type::{HashAlgorithm, KeyAlgorithm, WalletType};
+
+/// Hashes `data` with the given algorithm, for use by both the one-shot
+/// `Wallet::hash`/`sign`/`verify` methods and the streaming `Signer`/
+/// `Verifier` below. Keeping it in one place is what guarantees both paths
+/// sign over the same bytes.
+pub fn hash(data: &[u8], hash_algorithm: HashAlgorithm) -> Vec<u8> {
+    match hash_algorithm {
+        HashAlgorithm::Sha3_256 => {
+            let mut hasher = Sha3_256::new();
+            hasher.update(data);
+            hasher.finalize().to_vec()
+        }
+    }
+}
+
+/// Streaming signer, modeled on OpenSSL's `Signer`: feed data in with
+/// [`Signer::update`] as many times as needed, then consume it with
+/// [`Signer::sign_to_vec`]. Useful for signing payloads too large to hold
+/// in memory at once, such as file chunks read incrementally.
+pub struct Signer {
+    key_algorithm: KeyAlgorithm,
+    hash_algorithm: HashAlgorithm,
+    sk: Vec<u8>,
+    hasher: Sha3_256,
+}
+
+impl Signer {
+    pub fn new(sk: &[u8], w_type: &WalletType) -> Result<Signer> {
+        if sk.len() != w_type.key_algorithm.sk_len() {
+            return Err(WalletError::InvalidKeyLength {
+                expected: w_type.key_algorithm.sk_len(),
+                actual: sk.len(),
+            });
+        }
+        Ok(Signer {
+            key_algorithm: w_type.key_algorithm,
+            hash_algorithm: w_type.hash_algorithm,
+            sk: sk.to_vec(),
+            hasher: Sha3_256::new(),
+        })
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let HashAlgorithm::Sha3_256 = self.hash_algorithm;
+        self.hasher.update(data);
+    }
+
+    /// Consumes the accumulated data and produces a signature over its hash.
+    pub fn sign_to_vec(self) -> Result<Vec<u8>> {
+        let digest = self.hasher.finalize().to_vec();
+        sign_digest(&self.sk, &digest, self.key_algorithm)
+    }
+}
+
+/// Streaming counterpart to [`Signer`]: feed the same data in with
+/// [`Verifier::update`], then check a signature against the accumulated hash.
+pub struct Verifier {
+    key_algorithm: KeyAlgorithm,
+    pk: Vec<u8>,
+    hasher: Sha3_256,
+}
+
+impl Verifier {
+    pub fn new(pk: &[u8], w_type: &WalletType) -> Result<Verifier> {
+        if pk.len() != w_type.key_algorithm.pk_len() {
+            return Err(WalletError::InvalidKeyLength {
+                expected: w_type.key_algorithm.pk_len(),
+                actual: pk.len(),
+            });
+        }
+        let HashAlgorithm::Sha3_256 = w_type.hash_algorithm;
+        Ok(Verifier {
+            key_algorithm: w_type.key_algorithm,
+            pk: pk.to_vec(),
+            hasher: Sha3_256::new(),
+        })
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    pub fn verify(&self, sig: &[u8]) -> Result<bool> {
+        let digest = self.hasher.clone().finalize().to_vec();
+        verify_digest(&self.pk, &digest, sig, self.key_algorithm)
+    }
+}
+
+pub fn sign_digest(sk: &[u8], digest: &[u8], key_algorithm: KeyAlgorithm) -> Result<Vec<u8>> {
+    match key_algorithm {
+        KeyAlgorithm::Ed25519 => {
+            let sk_bytes: [u8; 32] = sk
+                .try_into()
+                .map_err(|_| WalletError::InvalidKeyLength {
+                    expected: 32,
+                    actual: sk.len(),
+                })?;
+            let signing_key = SigningKey::from_bytes(&sk_bytes);
+            let signature: Ed25519Signature = signing_key.sign(digest);
+            Ok(signature.to_bytes().to_vec())
+        }
+        KeyAlgorithm::Secp256k1 => {
+            let signing_key = Secp256k1SigningKey::from_slice(sk)
+                .map_err(|e| WalletError::Encoding(format!("invalid secp256k1 private key: {e}")))?;
+            // `Signer::sign` would hash `digest` again with SHA-256 before
+            // signing; `sign_prehash` signs these bytes directly so the
+            // signature is over `digest` itself, matching the Ed25519 branch.
+            let signature: Secp256k1Signature = signing_key
+                .sign_prehash(digest)
+                .map_err(|e| WalletError::Encoding(format!("secp256k1 signing failed: {e}")))?;
+            Ok(signature.to_bytes().to_vec())
+        }
+    }
+}
+
+pub fn verify_digest(
+    pk: &[u8],
+    digest: &[u8],
+    sig: &[u8],
+    key_algorithm: KeyAlgorithm,
+) -> Result<bool> {
+    match key_algorithm {
+        KeyAlgorithm::Ed25519 => {
+            let pk_bytes: [u8; 32] = pk
+                .try_into()
+                .map_err(|_| WalletError::InvalidKeyLength {
+                    expected: 32,
+                    actual: pk.len(),
+                })?;
+            let verifying_key = VerifyingKey::from_bytes(&pk_bytes)
+                .map_err(|e| WalletError::Encoding(format!("invalid ed25519 public key: {e}")))?;
+            let signature = Ed25519Signature::from_slice(sig)
+                .map_err(|e| WalletError::Encoding(format!("invalid ed25519 signature: {e}")))?;
+            Ok(verifying_key.verify(digest, &signature).is_ok())
+        }
+        KeyAlgorithm::Secp256k1 => {
+            let verifying_key = Secp256k1VerifyingKey::from_sec1_bytes(pk)
+                .map_err(|e| WalletError::Encoding(format!("invalid secp256k1 public key: {e}")))?;
+            let signature = Secp256k1Signature::from_slice(sig)
+                .map_err(|e| WalletError::Encoding(format!("invalid secp256k1 signature: {e}")))?;
+            Ok(verifying_key.verify_prehash(digest, &signature).is_ok())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet_type::Role;
+
+    fn secp256k1_signer() -> WalletType {
+        WalletType::new(Role::Signer, KeyAlgorithm::Secp256k1, HashAlgorithm::Sha3_256)
+    }
+
+    #[test]
+    fn streaming_signer_matches_one_shot_sign() {
+        let wallet = crate::wallet::Wallet::create_default_wallet().unwrap();
+
+        let mut streaming = Signer::new(&wallet.sk, &wallet.w_type).unwrap();
+        streaming.update(b"hello ");
+        streaming.update(b"wallet");
+        let signature = streaming.sign_to_vec().unwrap();
+
+        let mut verifier = Verifier::new(&wallet.pk, &wallet.w_type).unwrap();
+        verifier.update(b"hello wallet");
+        assert!(verifier.verify(&signature).unwrap());
+        assert_eq!(signature, wallet.sign(b"hello wallet").unwrap());
+    }
+
+    #[test]
+    fn secp256k1_streaming_signer_matches_one_shot_sign() {
+        let w_type = secp256k1_signer();
+        let wallet = crate::wallet::Wallet::from_wallet_type(&w_type).unwrap();
+
+        let mut streaming = Signer::new(&wallet.sk, &w_type).unwrap();
+        streaming.update(b"hello ");
+        streaming.update(b"wallet");
+        let signature = streaming.sign_to_vec().unwrap();
+
+        let mut verifier = Verifier::new(&wallet.pk, &w_type).unwrap();
+        verifier.update(b"hello wallet");
+        assert!(verifier.verify(&signature).unwrap());
+        assert_eq!(signature, wallet.sign(b"hello wallet").unwrap());
+    }
+
+    /// Regression test for signing over a second, implicit hash: checks the
+    /// signature against `digest` directly via the low-level prehash API
+    /// rather than through `verify_digest`, since a sign/verify pair that
+    /// both apply the same extra hash would otherwise still round-trip.
+    #[test]
+    fn secp256k1_sign_digest_signs_the_given_digest_directly() {
+        let w_type = secp256k1_signer();
+        let wallet = crate::wallet::Wallet::from_wallet_type(&w_type).unwrap();
+        let digest = hash(b"hello wallet", w_type.hash_algorithm);
+
+        let signature = sign_digest(&wallet.sk, &digest, w_type.key_algorithm).unwrap();
+        let signature = Secp256k1Signature::from_slice(&signature).unwrap();
+        let verifying_key = Secp256k1VerifyingKey::from_sec1_bytes(&wallet.pk).unwrap();
+
+        assert!(verifying_key.verify_prehash(&digest, &signature).is_ok());
+    }
+}
@@ -0,0 +1,296 @@
+use ed25519_dalek::{SigningKey, VerifyingKey};
+use k256::ecdsa::{SigningKey as Secp256k1SigningKey, VerifyingKey as Secp256k1VerifyingKey};
+
+use crate::address;
+use crate::error::{Result, WalletError};
+use crate::wallet_type::{KeyAlgorithm, Role, WalletType};
+
+/// A key pair plus the metadata needed to use it.
+///
+/// `sk` is empty for watch-only wallets built from a public key or address
+/// ([`Wallet::from_pk`], [`Wallet::from_address`]), and `pk` is also empty
+/// for a wallet built from an address alone; `w_type` and `address` are
+/// always populated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wallet {
+    pub w_type: WalletType,
+    pub sk: Vec<u8>,
+    pub pk: Vec<u8>,
+    pub address: String,
+}
+
+impl Wallet {
+    /// Generates a fresh signing wallet using [`WalletType::default_signer`].
+    pub fn create_default_wallet() -> Result<Wallet> {
+        Wallet::from_wallet_type(&WalletType::default_signer())
+    }
+
+    /// Generates a fresh signing wallet for the given `w_type`.
+    ///
+    /// `w_type.role` must be [`Role::Signer`]; a watch-only type has no key
+    /// material to generate.
+    pub fn from_wallet_type(w_type: &WalletType) -> Result<Wallet> {
+        if w_type.role != Role::Signer {
+            return Err(WalletError::SigningUnavailable);
+        }
+        let sk = generate_sk(w_type.key_algorithm);
+        Wallet::from_sk(&sk, w_type)
+    }
+
+    /// Rebuilds a wallet from a private key, recomputing `pk` and `address`
+    /// rather than trusting the caller for anything beyond the key bytes.
+    pub fn from_sk(sk: &[u8], w_type: &WalletType) -> Result<Wallet> {
+        if sk.len() != w_type.key_algorithm.sk_len() {
+            return Err(WalletError::InvalidKeyLength {
+                expected: w_type.key_algorithm.sk_len(),
+                actual: sk.len(),
+            });
+        }
+        let pk = derive_pk(sk, w_type.key_algorithm)?;
+        let address = address::derive_address(&pk, w_type)?;
+        Ok(Wallet {
+            w_type: *w_type,
+            sk: sk.to_vec(),
+            pk,
+            address,
+        })
+    }
+
+    /// Builds a watch-only wallet from a public key. `sk` is left empty, so
+    /// signing is unavailable.
+    pub fn from_pk(pk: &[u8], w_type: &WalletType) -> Result<Wallet> {
+        if pk.len() != w_type.key_algorithm.pk_len() {
+            return Err(WalletError::InvalidKeyLength {
+                expected: w_type.key_algorithm.pk_len(),
+                actual: pk.len(),
+            });
+        }
+        let watcher_type = WalletType::new(Role::Watcher, w_type.key_algorithm, w_type.hash_algorithm);
+        let address = address::derive_address(pk, &watcher_type)?;
+        Ok(Wallet {
+            w_type: watcher_type,
+            sk: Vec::new(),
+            pk: pk.to_vec(),
+            address,
+        })
+    }
+
+    /// Builds a watch-only wallet from just an address, with no key
+    /// material at all. Signing and verifying are both unavailable.
+    pub fn from_address(address: &str) -> Result<Wallet> {
+        address::validate_address(address)?;
+        Ok(Wallet {
+            w_type: WalletType::default_watcher(),
+            sk: Vec::new(),
+            pk: Vec::new(),
+            address: address.to_string(),
+        })
+    }
+
+    /// Hashes `message` with this wallet's configured hash algorithm.
+    pub fn hash(&self, message: &[u8]) -> Result<Vec<u8>> {
+        Ok(crate::signer::hash(message, self.w_type.hash_algorithm))
+    }
+
+    /// Signs `message` in one shot. Equivalent to feeding the whole message
+    /// through a [`crate::signer::Signer`] in a single `update` call.
+    pub fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        if self.sk.is_empty() {
+            return Err(WalletError::SigningUnavailable);
+        }
+        let digest = self.hash(message)?;
+        crate::signer::sign_digest(&self.sk, &digest, self.w_type.key_algorithm)
+    }
+
+    /// Verifies `signature` over `message` against this wallet's public key.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool> {
+        if self.pk.is_empty() {
+            return Err(WalletError::InvalidKeyLength {
+                expected: self.w_type.key_algorithm.pk_len(),
+                actual: 0,
+            });
+        }
+        let digest = self.hash(message)?;
+        crate::signer::verify_digest(&self.pk, &digest, signature, self.w_type.key_algorithm)
+    }
+
+    /// Serializes this wallet to a keystore-style JSON document. See
+    /// [`crate::keystore::to_json`] for the exact shape.
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        crate::keystore::to_json(self)
+    }
+
+    /// Rebuilds a wallet from a [`Wallet::to_json`] document, validating
+    /// that the embedded address matches the key material.
+    pub fn from_json(j: serde_json::Value) -> Result<Wallet> {
+        crate::keystore::from_json(j)
+    }
+
+    /// Normalizes an in-progress wallet in place: fills in `pk` from `sk`
+    /// when it's missing, then recomputes `address` from `pk`. For an
+    /// address-only wallet with no key material to derive from, instead
+    /// lowercases `address` (addresses are case-insensitive hex) and
+    /// validates the result, so a mixed-case or corrupted address is caught
+    /// here rather than on first use.
+    pub fn format_wallet(&mut self) -> Result<()> {
+        if self.pk.is_empty() && !self.sk.is_empty() {
+            self.pk = derive_pk(&self.sk, self.w_type.key_algorithm)?;
+        }
+        if !self.pk.is_empty() {
+            self.address = address::derive_address(&self.pk, &self.w_type)?;
+        } else {
+            self.address = self.address.to_lowercase();
+            address::validate_address(&self.address)?;
+        }
+        Ok(())
+    }
+}
+
+fn generate_sk(key_algorithm: KeyAlgorithm) -> Vec<u8> {
+    match key_algorithm {
+        KeyAlgorithm::Ed25519 => SigningKey::generate(&mut rand::rngs::OsRng)
+            .to_bytes()
+            .to_vec(),
+        KeyAlgorithm::Secp256k1 => {
+            Secp256k1SigningKey::random(&mut rand::rngs::OsRng).to_bytes().to_vec()
+        }
+    }
+}
+
+fn derive_pk(sk: &[u8], key_algorithm: KeyAlgorithm) -> Result<Vec<u8>> {
+    match key_algorithm {
+        KeyAlgorithm::Ed25519 => {
+            let sk_bytes: [u8; 32] = sk.try_into().map_err(|_| WalletError::InvalidKeyLength {
+                expected: 32,
+                actual: sk.len(),
+            })?;
+            let signing_key = SigningKey::from_bytes(&sk_bytes);
+            let verifying_key: VerifyingKey = signing_key.verifying_key();
+            Ok(verifying_key.to_bytes().to_vec())
+        }
+        KeyAlgorithm::Secp256k1 => {
+            let signing_key = Secp256k1SigningKey::from_slice(sk)
+                .map_err(|e| WalletError::Encoding(format!("invalid secp256k1 private key: {e}")))?;
+            let verifying_key: Secp256k1VerifyingKey = *signing_key.verifying_key();
+            Ok(verifying_key.to_encoded_point(true).as_bytes().to_vec())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet_type::HashAlgorithm;
+
+    fn secp256k1_signer() -> WalletType {
+        WalletType::new(Role::Signer, KeyAlgorithm::Secp256k1, HashAlgorithm::Sha3_256)
+    }
+
+    #[test]
+    fn from_sk_reproduces_an_equal_wallet() {
+        let original = Wallet::create_default_wallet().unwrap();
+        let rebuilt = Wallet::from_sk(&original.sk, &original.w_type).unwrap();
+        assert_eq!(original, rebuilt);
+    }
+
+    #[test]
+    fn from_pk_has_no_signing_key() {
+        let original = Wallet::create_default_wallet().unwrap();
+        let watcher = Wallet::from_pk(&original.pk, &original.w_type).unwrap();
+        assert!(watcher.sk.is_empty());
+        assert_eq!(watcher.address, original.address);
+    }
+
+    #[test]
+    fn from_sk_rejects_wrong_length() {
+        let w_type = WalletType::default_signer();
+        assert!(Wallet::from_sk(&[0u8; 4], &w_type).is_err());
+    }
+
+    #[test]
+    fn secp256k1_from_sk_reproduces_an_equal_wallet() {
+        let w_type = secp256k1_signer();
+        let original = Wallet::from_wallet_type(&w_type).unwrap();
+        let rebuilt = Wallet::from_sk(&original.sk, &original.w_type).unwrap();
+        assert_eq!(original, rebuilt);
+    }
+
+    #[test]
+    fn secp256k1_from_pk_has_no_signing_key() {
+        let w_type = secp256k1_signer();
+        let original = Wallet::from_wallet_type(&w_type).unwrap();
+        let watcher = Wallet::from_pk(&original.pk, &original.w_type).unwrap();
+        assert!(watcher.sk.is_empty());
+        assert_eq!(watcher.address, original.address);
+    }
+
+    #[test]
+    fn sign_then_verify_round_trips() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        let signature = wallet.sign(b"hello wallet").unwrap();
+        assert!(wallet.verify(b"hello wallet", &signature).unwrap());
+        assert!(!wallet.verify(b"tampered", &signature).unwrap());
+    }
+
+    #[test]
+    fn secp256k1_sign_then_verify_round_trips() {
+        let wallet = Wallet::from_wallet_type(&secp256k1_signer()).unwrap();
+        let signature = wallet.sign(b"hello wallet").unwrap();
+        assert!(wallet.verify(b"hello wallet", &signature).unwrap());
+        assert!(!wallet.verify(b"tampered", &signature).unwrap());
+    }
+
+    #[test]
+    fn watch_only_wallet_cannot_sign() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        let watcher = Wallet::from_pk(&wallet.pk, &wallet.w_type).unwrap();
+        assert!(watcher.sign(b"hello wallet").is_err());
+    }
+
+    #[test]
+    fn format_wallet_fills_in_pk_and_address() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        let mut partial = Wallet {
+            w_type: wallet.w_type,
+            sk: wallet.sk.clone(),
+            pk: Vec::new(),
+            address: String::new(),
+        };
+        partial.format_wallet().unwrap();
+        assert_eq!(partial.pk, wallet.pk);
+        assert_eq!(partial.address, wallet.address);
+    }
+
+    #[test]
+    fn format_wallet_lowercases_and_validates_an_address_only_wallet() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        let mut address_only = Wallet::from_address(&wallet.address).unwrap();
+        address_only.address = address_only.address.to_uppercase();
+
+        address_only.format_wallet().unwrap();
+        assert_eq!(address_only.address, wallet.address);
+    }
+
+    #[test]
+    fn format_wallet_rejects_a_corrupted_address_only_wallet() {
+        let wallet = Wallet::create_default_wallet().unwrap();
+        let mut address_only = Wallet::from_address(&wallet.address).unwrap();
+        let first_char = if address_only.address.starts_with('0') { '1' } else { '0' };
+        address_only.address.replace_range(0..1, &first_char.to_string());
+
+        assert!(address_only.format_wallet().is_err());
+    }
+
+    #[test]
+    fn from_sk_rejects_wrong_length_with_invalid_key_length_error() {
+        let w_type = WalletType::default_signer();
+        let err = Wallet::from_sk(&[0u8; 4], &w_type).unwrap_err();
+        assert_eq!(
+            err,
+            WalletError::InvalidKeyLength {
+                expected: 32,
+                actual: 4,
+            }
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::str::FromStr;
+
+use crate::error::WalletError;
+
+/// The role a wallet plays, independent of which keys it holds.
+///
+/// `Watcher` wallets are constructed from a public key or address only and
+/// can never sign; `Signer` wallets hold a private key and can.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Signer,
+    Watcher,
+}
+
+impl Role {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::Signer => "signer",
+            Role::Watcher => "watcher",
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = WalletError;
+
+    fn from_str(s: &str) -> Result<Role, WalletError> {
+        match s {
+            "signer" => Ok(Role::Signer),
+            "watcher" => Ok(Role::Watcher),
+            other => Err(WalletError::UnsupportedAlgorithm(format!("role: {other}"))),
+        }
+    }
+}
+
+/// Asymmetric key algorithm used to derive `pk` from `sk`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAlgorithm {
+    Ed25519,
+    Secp256k1,
+}
+
+impl KeyAlgorithm {
+    /// Length in bytes of a private key for this algorithm.
+    pub fn sk_len(self) -> usize {
+        match self {
+            KeyAlgorithm::Ed25519 => 32,
+            KeyAlgorithm::Secp256k1 => 32,
+        }
+    }
+
+    /// Length in bytes of a public key for this algorithm.
+    pub fn pk_len(self) -> usize {
+        match self {
+            KeyAlgorithm::Ed25519 => 32,
+            KeyAlgorithm::Secp256k1 => 33,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            KeyAlgorithm::Ed25519 => "ed25519",
+            KeyAlgorithm::Secp256k1 => "secp256k1",
+        }
+    }
+}
+
+impl FromStr for KeyAlgorithm {
+    type Err = WalletError;
+
+    fn from_str(s: &str) -> Result<KeyAlgorithm, WalletError> {
+        match s {
+            "ed25519" => Ok(KeyAlgorithm::Ed25519),
+            "secp256k1" => Ok(KeyAlgorithm::Secp256k1),
+            other => Err(WalletError::UnsupportedAlgorithm(format!("key algorithm: {other}"))),
+        }
+    }
+}
+
+/// Hash algorithm used for address derivation and message signing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha3_256,
+}
+
+impl HashAlgorithm {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha3_256 => "sha3-256",
+        }
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = WalletError;
+
+    fn from_str(s: &str) -> Result<HashAlgorithm, WalletError> {
+        match s {
+            "sha3-256" => Ok(HashAlgorithm::Sha3_256),
+            other => Err(WalletError::UnsupportedAlgorithm(format!("hash algorithm: {other}"))),
+        }
+    }
+}
+
+/// Describes how a wallet's keys and address are derived and used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WalletType {
+    pub role: Role,
+    pub key_algorithm: KeyAlgorithm,
+    pub hash_algorithm: HashAlgorithm,
+}
+
+impl WalletType {
+    pub fn new(role: Role, key_algorithm: KeyAlgorithm, hash_algorithm: HashAlgorithm) -> Self {
+        WalletType {
+            role,
+            key_algorithm,
+            hash_algorithm,
+        }
+    }
+
+    /// The common case: a signing wallet using Ed25519 over SHA3-256.
+    pub fn default_signer() -> Self {
+        WalletType::new(Role::Signer, KeyAlgorithm::Ed25519, HashAlgorithm::Sha3_256)
+    }
+
+    /// Same algorithms as [`WalletType::default_signer`] but for a
+    /// watch-only wallet with no private key.
+    pub fn default_watcher() -> Self {
+        WalletType::new(Role::Watcher, KeyAlgorithm::Ed25519, HashAlgorithm::Sha3_256)
+    }
+}